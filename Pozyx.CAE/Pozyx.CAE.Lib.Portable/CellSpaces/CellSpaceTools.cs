@@ -1,4 +1,5 @@
 ﻿using System;
+using System.Collections;
 using System.Collections.Generic;
 using System.IO;
 using System.Linq;
@@ -32,6 +33,49 @@ namespace Pozyx.CAE.Lib.CellSpaces
             }
         }
 
+        // '0' is dead, '1' is live cell, e.g. "0110" for IRunner.Create initial state
+        public static BitArray ParseInitialState(string cells)
+        {
+            if (cells == null)
+                throw new ArgumentNullException(nameof(cells));
+
+            if (cells.Length == 0)
+                throw new ArgumentException("Initial state cannot be empty", nameof(cells));
+
+            var bitArray = new BitArray(cells.Length);
+
+            for (var i = 0; i < cells.Length; i++)
+            {
+                if (cells[i] != '0' && cells[i] != '1')
+                    throw new ArgumentException($"Invalid cell '{cells[i]}' at position {i}, only '0' and '1' allowed", nameof(cells));
+
+                bitArray[i] = cells[i] == '1';
+            }
+
+            return bitArray;
+        }
+
+        // whole input is one row, surrounding whitespace (e.g. trailing new line from a pipe) is ignored
+        public static BitArray ReadInitialState(TextReader reader)
+        {
+            if (reader == null)
+                throw new ArgumentNullException(nameof(reader));
+
+            return ParseInitialState(reader.ReadToEnd().Trim());
+        }
+
+        // copy so that later changes by caller don't affect the run
+        internal static BitArray CopyInitialState(BitArray initialState)
+        {
+            if (initialState == null)
+                throw new ArgumentNullException(nameof(initialState));
+
+            if (initialState.Length == 0)
+                throw new ArgumentException("Initial state cannot be empty", nameof(initialState));
+
+            return new BitArray(initialState);
+        }
+
         internal static void GetChangeBounds(
             ICellSpace prevStep,
             ICellSpace nextStep,
@@ -0,0 +1,87 @@
+﻿using System;
+using System.Collections;
+
+namespace Pozyx.CAE.Lib.CellSpaces
+{
+    // one long covers 64 cells
+    // bits past Length in the last long are always 0
+    public class PackedLongArrayCellSpace : ICellSpace
+    {
+        // log2 of bits in long, for shift / mask based indexing
+        internal const int BitsInLongShift = 6;
+
+        internal const int BitsInLong = 1 << BitsInLongShift;
+
+        public long[] PackedCells { get; private set; }
+
+        public int Offset { get; private set; }
+
+        public int Length { get; private set; }
+
+        public void Initialize(BitArray bitArray, int offset)
+        {
+            Initialize(bitArray.Length, offset);
+
+            for (var i = 0; i < bitArray.Length; i++)
+            {
+                int arrayIndex;
+                int longIndex;
+                GetPackedIndex(i, out arrayIndex, out longIndex);
+
+                if (bitArray[i])
+                    PackedCells[arrayIndex] |= (1L << longIndex);
+                else
+                    PackedCells[arrayIndex] &= ~(1L << longIndex);
+            }
+        }
+
+        public void Initialize(int length, int offset)
+        {
+            Length = length;
+            PackedCells = new long[GetPackedLength(Length)];
+            Offset = offset;
+        }
+
+        public bool Get(int index)
+        {
+            index -= Offset;
+
+            if (index < 0 || index >= Length)
+                return false;
+
+            int arrayIndex;
+            int longIndex;
+            GetPackedIndex(index, out arrayIndex, out longIndex);
+
+            return (PackedCells[arrayIndex] & (1L << longIndex)) != 0;
+        }
+
+        public void Set(int index, bool value)
+        {
+            index -= Offset;
+
+            if (index < 0 || index >= Length)
+                throw new Exception("Invalid index to write");
+
+            int arrayIndex;
+            int longIndex;
+            GetPackedIndex(index, out arrayIndex, out longIndex);
+
+            if (value)
+                PackedCells[arrayIndex] |= (1L << longIndex);
+            else
+                PackedCells[arrayIndex] &= ~(1L << longIndex);
+        }
+
+        private int GetPackedLength(int length)
+        {
+            return (int) Math.Ceiling((double) length / BitsInLong);
+        }
+
+        private void GetPackedIndex(int index, out int arrayIndex, out int longIndex)
+        {
+            arrayIndex = index / BitsInLong;
+            longIndex = index % BitsInLong;
+        }
+    }
+}
@@ -0,0 +1,29 @@
+﻿using System;
+using System.Collections;
+
+namespace Pozyx.CAE.Lib.InitialStates
+{
+    // repeats blockSize live cells followed by blockSize dead cells
+    public class BlockInitialStateGenerator : IInitialStateGenerator
+    {
+        private readonly int _blockSize;
+
+        public BlockInitialStateGenerator(int blockSize)
+        {
+            if (blockSize < 1)
+                throw new ArgumentOutOfRangeException(nameof(blockSize), "Block size must be positive");
+
+            _blockSize = blockSize;
+        }
+
+        public BitArray Generate(int length)
+        {
+            var bitArray = new BitArray(length);
+
+            for (var i = 0; i < length; i++)
+                bitArray[i] = (i / _blockSize) % 2 == 0;
+
+            return bitArray;
+        }
+    }
+}
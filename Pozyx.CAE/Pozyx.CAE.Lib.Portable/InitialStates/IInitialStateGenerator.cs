@@ -0,0 +1,10 @@
+﻿using System.Collections;
+
+namespace Pozyx.CAE.Lib.InitialStates
+{
+    // produces generation 0 for IRunner.Create
+    public interface IInitialStateGenerator
+    {
+        BitArray Generate(int length);
+    }
+}
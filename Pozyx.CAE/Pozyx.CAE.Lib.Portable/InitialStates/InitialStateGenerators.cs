@@ -0,0 +1,42 @@
+﻿using System;
+using System.Globalization;
+
+namespace Pozyx.CAE.Lib.InitialStates
+{
+    public static class InitialStateGenerators
+    {
+        // names: alternating, block(n), random-walk, primes
+        // seed is used by random-walk only
+        public static IInitialStateGenerator GetGenerator(string name, int seed = 0)
+        {
+            if (name == null)
+                throw new ArgumentNullException(nameof(name));
+
+            switch (name)
+            {
+                case "alternating":
+                    return new BlockInitialStateGenerator(1);
+                case "random-walk":
+                    return new RandomWalkInitialStateGenerator(seed);
+                case "primes":
+                    return new PrimesInitialStateGenerator();
+            }
+
+            const string blockPrefix = "block(";
+
+            if (name.StartsWith(blockPrefix, StringComparison.Ordinal) && name.EndsWith(")", StringComparison.Ordinal))
+            {
+                var blockSizeString = name.Substring(blockPrefix.Length, name.Length - blockPrefix.Length - 1);
+
+                int blockSize;
+
+                if (!int.TryParse(blockSizeString, NumberStyles.None, CultureInfo.InvariantCulture, out blockSize) || blockSize < 1)
+                    throw new ArgumentException($"Invalid block size '{blockSizeString}'", nameof(name));
+
+                return new BlockInitialStateGenerator(blockSize);
+            }
+
+            throw new ArgumentException($"Unknown initial state generator '{name}'", nameof(name));
+        }
+    }
+}
@@ -0,0 +1,28 @@
+﻿using System.Collections;
+
+namespace Pozyx.CAE.Lib.InitialStates
+{
+    // cell i is live when i is prime
+    public class PrimesInitialStateGenerator : IInitialStateGenerator
+    {
+        public BitArray Generate(int length)
+        {
+            // sieve of Eratosthenes
+            var bitArray = new BitArray(length, true);
+
+            for (var i = 0; i < length && i < 2; i++)
+                bitArray[i] = false;
+
+            for (var i = 2; (long) i * i < length; i++)
+            {
+                if (!bitArray[i])
+                    continue;
+
+                for (var multiple = i * i; multiple < length; multiple += i)
+                    bitArray[multiple] = false;
+            }
+
+            return bitArray;
+        }
+    }
+}
@@ -0,0 +1,44 @@
+﻿using System;
+using System.Collections;
+
+namespace Pozyx.CAE.Lib.InitialStates
+{
+    // walker starts in the middle and moves one cell left or right per step (bouncing off the edges),
+    // every visited cell is live
+    public class RandomWalkInitialStateGenerator : IInitialStateGenerator
+    {
+        private readonly int _seed;
+
+        public RandomWalkInitialStateGenerator(int seed)
+        {
+            _seed = seed;
+        }
+
+        public BitArray Generate(int length)
+        {
+            var bitArray = new BitArray(length);
+
+            if (length == 0)
+                return bitArray;
+
+            var random = new Random(_seed);
+            var position = length / 2;
+
+            bitArray[position] = true;
+
+            for (var step = 1; step < length; step++)
+            {
+                position += random.Next(2) == 0 ? -1 : 1;
+
+                if (position < 0)
+                    position = length > 1 ? 1 : 0;
+                else if (position >= length)
+                    position = length > 1 ? length - 2 : 0;
+
+                bitArray[position] = true;
+            }
+
+            return bitArray;
+        }
+    }
+}
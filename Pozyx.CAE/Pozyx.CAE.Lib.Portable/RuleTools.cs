@@ -44,6 +44,22 @@ namespace Pozyx.CAE.Lib
                 (rightValue ? 1 : 0)];
         }
 
+        internal static bool[][] GetBoolArraysForRules(int[] ruleNumbers)
+        {
+            if (ruleNumbers == null)
+                throw new ArgumentNullException(nameof(ruleNumbers));
+
+            if (ruleNumbers.Length == 0)
+                throw new ArgumentException("At least one rule expected", nameof(ruleNumbers));
+
+            var rules = new bool[ruleNumbers.Length][];
+
+            for (var i = 0; i < ruleNumbers.Length; i++)
+                rules[i] = GetBoolArrayForRule(ruleNumbers[i]);
+
+            return rules;
+        }
+
         internal static bool[] GetBoolArrayForRule(int ruleNumber)
         {
             if (ruleNumber < 0 || ruleNumber > 255)
@@ -57,6 +73,29 @@ namespace Pozyx.CAE.Lib
             return bools;
         }
 
+        // transitions ordered from neighborhood 111 down to 000 (as in Wolfram's notation),
+        // e.g. "01101110" is rule 110
+        public static int GetRuleNumberForTable(string ruleTable)
+        {
+            if (ruleTable == null)
+                throw new ArgumentNullException(nameof(ruleTable));
+
+            if (ruleTable.Length != 8)
+                throw new ArgumentException("8 transitions expected", nameof(ruleTable));
+
+            var ruleNumber = 0;
+
+            foreach (var transition in ruleTable)
+            {
+                if (transition != '0' && transition != '1')
+                    throw new ArgumentException($"Invalid transition '{transition}', only '0' and '1' allowed", nameof(ruleTable));
+
+                ruleNumber = (ruleNumber << 1) | (transition == '1' ? 1 : 0);
+            }
+
+            return ruleNumber;
+        }
+
         public static byte ConvertBitsToByte(bool[] bits)
         {
             if (bits.Length != 8)
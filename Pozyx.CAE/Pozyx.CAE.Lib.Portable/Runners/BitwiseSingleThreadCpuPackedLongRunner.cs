@@ -0,0 +1,74 @@
+﻿using Pozyx.CAE.Lib.CellSpaces;
+
+namespace Pozyx.CAE.Lib.Runners
+{
+    // computes 64 cells at once - every neighborhood that the rule maps to 1
+    // is matched against whole words of left / center / right neighbors
+    public class BitwiseSingleThreadCpuPackedLongRunner : StepCpuSyncedRunner<PackedLongArrayCellSpace>
+    {
+        protected override void RunStep(PackedLongArrayCellSpace inputCellSpace, PackedLongArrayCellSpace outputCellSpace, bool[] rule)
+        {
+            const int bitsInLong = PackedLongArrayCellSpace.BitsInLong;
+
+            var offsetDifference = outputCellSpace.Offset - inputCellSpace.Offset;
+            var outputCells = outputCellSpace.PackedCells;
+            var lastWordBits = outputCellSpace.Length % bitsInLong;
+
+            for (var arrayIndex = 0; arrayIndex < outputCells.Length; arrayIndex++)
+            {
+                var inputIndex = arrayIndex * bitsInLong + offsetDifference;
+
+                var leftValues = GetWord(inputCellSpace, inputIndex - 1);
+                var values = GetWord(inputCellSpace, inputIndex);
+                var rightValues = GetWord(inputCellSpace, inputIndex + 1);
+
+                long newValues = 0;
+
+                for (var neighborhood = 0; neighborhood < rule.Length; neighborhood++)
+                {
+                    if (!rule[neighborhood])
+                        continue;
+
+                    newValues |=
+                        ((neighborhood & 4) != 0 ? leftValues : ~leftValues) &
+                        ((neighborhood & 2) != 0 ? values : ~values) &
+                        ((neighborhood & 1) != 0 ? rightValues : ~rightValues);
+                }
+
+                // keep bits past Length cleared so that next step reads them as dead cells
+                if (arrayIndex == outputCells.Length - 1 && lastWordBits != 0)
+                    newValues &= (1L << lastWordBits) - 1;
+
+                outputCells[arrayIndex] = newValues;
+            }
+        }
+
+        // returns 64 cells starting at index (relative to cell space start), cells outside are 0
+        private static long GetWord(PackedLongArrayCellSpace cellSpace, int index)
+        {
+            const int bitsInLong = PackedLongArrayCellSpace.BitsInLong;
+            const int bitsInLongShift = PackedLongArrayCellSpace.BitsInLongShift;
+
+            if (index >= cellSpace.Length || index <= -bitsInLong)
+                return 0;
+
+            // arithmetic shift and mask round towards negative infinity (unlike / and %)
+            var arrayIndex = index >> bitsInLongShift;
+            var longIndex = index & (bitsInLong - 1);
+
+            var lowWord = GetPackedCell(cellSpace.PackedCells, arrayIndex);
+
+            if (longIndex == 0)
+                return lowWord;
+
+            var highWord = GetPackedCell(cellSpace.PackedCells, arrayIndex + 1);
+
+            return (long) (((ulong) lowWord >> longIndex) | ((ulong) highWord << (bitsInLong - longIndex)));
+        }
+
+        private static long GetPackedCell(long[] packedCells, int arrayIndex)
+        {
+            return arrayIndex >= 0 && arrayIndex < packedCells.Length ? packedCells[arrayIndex] : 0;
+        }
+    }
+}
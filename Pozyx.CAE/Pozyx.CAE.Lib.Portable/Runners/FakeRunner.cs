@@ -10,8 +10,19 @@ using Pozyx.CAE.Lib.CellSpaces;
 
 namespace Pozyx.CAE.Lib.Runners
 {
+    // emits random rows, rules and initial state are ignored
     public class FakeRunner<TCellSpace> : IRunner<TCellSpace> where TCellSpace : ICellSpace, new()
     {
+        public IConnectableObservable<TCellSpace> Create(int[] ruleNumbers, BitArray initialState, int offset, CancellationToken ct)
+        {
+            return Create(0, ct);
+        }
+
+        public IConnectableObservable<TCellSpace> Create(int ruleNumber, BitArray initialState, int offset, CancellationToken ct)
+        {
+            return Create(ruleNumber, ct);
+        }
+
         public IConnectableObservable<TCellSpace> Create(int ruleNumber, CancellationToken ct)
         {
             return Observable.Create<TCellSpace>(observer =>
@@ -1,4 +1,5 @@
 ﻿using System;
+using System.Collections;
 using System.Reactive.Subjects;
 using System.Threading;
 using Pozyx.CAE.Lib.CellSpaces;
@@ -7,6 +8,17 @@ namespace Pozyx.CAE.Lib.Runners
 {
     public interface IRunner<out TCellSpace> : IDisposable where TCellSpace : ICellSpace, new()
     {
+        // generation 0 is a single live cell at 0
         IConnectableObservable<TCellSpace> Create(int ruleNumber, CancellationToken ct);
+
+        // initialState is generation 0, its first cell is placed at offset
+        // (AMP runners in Pozyx.CAE.Lib.Windows were not run with anything but the single cell,
+        //   tiled ones pass padded array lengths to native code)
+        IConnectableObservable<TCellSpace> Create(int ruleNumber, BitArray initialState, int offset, CancellationToken ct);
+
+        // rule schedule - ruleNumbers[i % ruleNumbers.Length] computes generation i + 1 from generation i,
+        //   e.g. { 30, 90 } computes generations 1, 3, 5, ... by rule 30 and 2, 4, 6, ... by rule 90
+        // (AMP runners were not run with a schedule either)
+        IConnectableObservable<TCellSpace> Create(int[] ruleNumbers, BitArray initialState, int offset, CancellationToken ct);
     }
 }
@@ -18,14 +18,25 @@ namespace Pozyx.CAE.Lib.Runners
 
         public IConnectableObservable<TCellSpace> Create(int ruleNumber, CancellationToken ct)
         {
-            var rule = RuleTools.GetBoolArrayForRule(ruleNumber);
+            return Create(ruleNumber, new BitArray(1, true), 0, ct);
+        }
+
+        public IConnectableObservable<TCellSpace> Create(int ruleNumber, BitArray initialState, int offset, CancellationToken ct)
+        {
+            return Create(new[] { ruleNumber }, initialState, offset, ct);
+        }
+
+        public IConnectableObservable<TCellSpace> Create(int[] ruleNumbers, BitArray initialState, int offset, CancellationToken ct)
+        {
+            var rules = RuleTools.GetBoolArraysForRules(ruleNumbers);
+            initialState = CellSpaceTools.CopyInitialState(initialState);
 
             return Observable.Create<TCellSpace>(observer =>
             {
                 Task.Run(() =>
                 {
                     InitThread();
-                    Run(observer, rule, ct);
+                    Run(observer, rules, initialState, offset, ct);
                 },
                  ct)
                 .ContinueWith(t =>
@@ -42,14 +53,16 @@ namespace Pozyx.CAE.Lib.Runners
             .Publish();
         }
 
-        private void Run(IObserver<TCellSpace> observer, bool[] rule, CancellationToken ct)
+        private void Run(IObserver<TCellSpace> observer, bool[][] rules, BitArray initialState, int offset, CancellationToken ct)
         {
             var prevStep = new TCellSpace();
-            prevStep.Initialize(new BitArray(1, true), 0);
+            prevStep.Initialize(initialState, offset);
             observer.OnNext(prevStep);
 
-            int? leftMostChangedIndex = 0;
-            int? rightMostChangedIndex = 0;
+            int? leftMostChangedIndex = offset;
+            int? rightMostChangedIndex = offset + initialState.Length - 1;
+
+            var ruleIndex = 0;
 
             while (true)
             {
@@ -67,6 +80,9 @@ namespace Pozyx.CAE.Lib.Runners
                 var nextStep = new TCellSpace();
                 nextStep.Initialize(nextStepLength, nextStepOffset);
 
+                var rule = rules[ruleIndex];
+                ruleIndex = (ruleIndex + 1) % rules.Length;
+
                 RunStep(prevStep, nextStep, rule);
 
                 observer.OnNext(nextStep);
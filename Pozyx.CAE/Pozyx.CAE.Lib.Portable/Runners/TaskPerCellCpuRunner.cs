@@ -17,13 +17,24 @@ namespace Pozyx.CAE.Lib.Runners
     {
         public IConnectableObservable<BoolArrayCellSpace> Create(int ruleNumber, CancellationToken ct)
         {
-            var rule = RuleTools.GetBoolArrayForRule(ruleNumber);
+            return Create(ruleNumber, new BitArray(1, true), 0, ct);
+        }
+
+        public IConnectableObservable<BoolArrayCellSpace> Create(int ruleNumber, BitArray initialState, int offset, CancellationToken ct)
+        {
+            return Create(new[] { ruleNumber }, initialState, offset, ct);
+        }
+
+        public IConnectableObservable<BoolArrayCellSpace> Create(int[] ruleNumbers, BitArray initialState, int offset, CancellationToken ct)
+        {
+            var rules = RuleTools.GetBoolArraysForRules(ruleNumbers);
+            initialState = CellSpaceTools.CopyInitialState(initialState);
 
             return Observable.Create<BoolArrayCellSpace>(observer =>
             {
                 var finishCellsCts = new CancellationTokenSource();
 
-                Task.Run(() => Run(observer, rule, ct, finishCellsCts.Token), ct)
+                Task.Run(() => Run(observer, rules, initialState, offset, ct, finishCellsCts.Token), ct)
                 .ContinueWith(t =>
                 {
                     if (t.IsCanceled)
@@ -41,7 +52,9 @@ namespace Pozyx.CAE.Lib.Runners
 
         private static void Run(
             IObserver<BoolArrayCellSpace> observer,
-            bool[] rule,
+            bool[][] rules,
+            BitArray initialState,
+            int offset,
             CancellationToken ct,
             CancellationToken finishCellsCt)
         {
@@ -51,12 +64,16 @@ namespace Pozyx.CAE.Lib.Runners
             var cellTasks = new Dictionary<int, Task>();
 
             var prevStep = new BoolArrayCellSpace();
-            prevStep.Initialize(new BitArray(1, true), 0);
+            prevStep.Initialize(initialState, offset);
             observer.OnNext(prevStep);
             BoolArrayCellSpace nextStep;
 
-            int? leftMostChangedIndex = 0;
-            int? rightMostChangedIndex = 0;
+            int? leftMostChangedIndex = offset;
+            int? rightMostChangedIndex = offset + initialState.Length - 1;
+
+            // captured by cell tasks, set for every step before they are released
+            bool[] rule = null;
+            var ruleIndex = 0;
 
             while (true)
             {
@@ -79,6 +96,9 @@ namespace Pozyx.CAE.Lib.Runners
                 nextStep = new BoolArrayCellSpace();
                 nextStep.Initialize(nextStepLength, nextStepOffset);
 
+                rule = rules[ruleIndex];
+                ruleIndex = (ruleIndex + 1) % rules.Length;
+
                 for (var index = nextStepOffset; index < nextStepOffset + nextStepLength; index++)
                 {
                     if (!cellTasks.ContainsKey(index))
@@ -15,13 +15,24 @@ namespace Pozyx.CAE.Lib.Runners
     {
         public IConnectableObservable<BoolArrayCellSpace> Create(int ruleNumber, CancellationToken ct)
         {
-            var rule = RuleTools.GetBoolArrayForRule(ruleNumber);
+            return Create(ruleNumber, new BitArray(1, true), 0, ct);
+        }
+
+        public IConnectableObservable<BoolArrayCellSpace> Create(int ruleNumber, BitArray initialState, int offset, CancellationToken ct)
+        {
+            return Create(new[] { ruleNumber }, initialState, offset, ct);
+        }
+
+        public IConnectableObservable<BoolArrayCellSpace> Create(int[] ruleNumbers, BitArray initialState, int offset, CancellationToken ct)
+        {
+            var rules = RuleTools.GetBoolArraysForRules(ruleNumbers);
+            initialState = CellSpaceTools.CopyInitialState(initialState);
 
             return Observable.Create<BoolArrayCellSpace>(observer =>
             {
                 var finishCellsCts = new CancellationTokenSource();
 
-                Task.Run(() => Run(observer, rule, ct, finishCellsCts.Token), ct)
+                Task.Run(() => Run(observer, rules, initialState, offset, ct, finishCellsCts.Token), ct)
                 .ContinueWith(t =>
                 {
                     if (t.IsCanceled)
@@ -39,7 +50,9 @@ namespace Pozyx.CAE.Lib.Runners
 
         private static void Run(
             IObserver<BoolArrayCellSpace> observer, 
-            bool[] rule, 
+            bool[][] rules, 
+            BitArray initialState,
+            int offset,
             CancellationToken ct,
             CancellationToken finishCellsCt)
         {
@@ -49,12 +62,16 @@ namespace Pozyx.CAE.Lib.Runners
             var cellTasks = new List<Task>(Environment.ProcessorCount);
 
             var prevStep = new BoolArrayCellSpace();
-            prevStep.Initialize(new BitArray(1, true), 0);
+            prevStep.Initialize(initialState, offset);
             observer.OnNext(prevStep);
             BoolArrayCellSpace nextStep = null;
 
-            int? leftMostChangedIndex = 0;
-            int? rightMostChangedIndex = 0;
+            int? leftMostChangedIndex = offset;
+            int? rightMostChangedIndex = offset + initialState.Length - 1;
+
+            // captured by cell tasks, set for every step before they are released
+            bool[] rule = null;
+            var ruleIndex = 0;
 
             var iterationsPerCore = -1;
             var nextStepLength = -1;
@@ -115,6 +132,9 @@ namespace Pozyx.CAE.Lib.Runners
                 nextStep = new BoolArrayCellSpace();
                 nextStep.Initialize(nextStepLength, nextStepOffset);             
 
+                rule = rules[ruleIndex];
+                ruleIndex = (ruleIndex + 1) % rules.Length;
+
                 iterationsPerCore = nextStepLength / Environment.ProcessorCount;
 
                 beginManualResetEvent.Set();
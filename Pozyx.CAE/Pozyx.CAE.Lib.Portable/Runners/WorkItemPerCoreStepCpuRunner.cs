@@ -16,11 +16,22 @@ namespace Pozyx.CAE.Lib.Runners
 
         public IConnectableObservable<BoolArrayCellSpace> Create(int ruleNumber, CancellationToken ct)
         {
-            var rule = RuleTools.GetBoolArrayForRule(ruleNumber);
+            return Create(ruleNumber, new BitArray(1, true), 0, ct);
+        }
+
+        public IConnectableObservable<BoolArrayCellSpace> Create(int ruleNumber, BitArray initialState, int offset, CancellationToken ct)
+        {
+            return Create(new[] { ruleNumber }, initialState, offset, ct);
+        }
+
+        public IConnectableObservable<BoolArrayCellSpace> Create(int[] ruleNumbers, BitArray initialState, int offset, CancellationToken ct)
+        {
+            var rules = RuleTools.GetBoolArraysForRules(ruleNumbers);
+            initialState = CellSpaceTools.CopyInitialState(initialState);
 
             return Observable.Create<BoolArrayCellSpace>(observer =>
             {
-                Task.Run(() => Run(observer, rule, ct), ct)
+                Task.Run(() => Run(observer, rules, initialState, offset, ct), ct)
                 .ContinueWith(t =>
                 {
                     if (t.IsCanceled)
@@ -35,15 +46,17 @@ namespace Pozyx.CAE.Lib.Runners
             .Publish();
         }
 
-        private void Run(IObserver<BoolArrayCellSpace> observer, bool[] rule, CancellationToken ct)
+        private void Run(IObserver<BoolArrayCellSpace> observer, bool[][] rules, BitArray initialState, int offset, CancellationToken ct)
         {
             var prevStep = new BoolArrayCellSpace();
-            prevStep.Initialize(new BitArray(1, true), 0);
+            prevStep.Initialize(initialState, offset);
             observer.OnNext(prevStep);
             BoolArrayCellSpace nextStep;
 
-            int? leftMostChangedIndex = 0;
-            int? rightMostChangedIndex = 0;
+            int? leftMostChangedIndex = offset;
+            int? rightMostChangedIndex = offset + initialState.Length - 1;
+
+            var ruleIndex = 0;
 
             while (true)
             {
@@ -61,6 +74,9 @@ namespace Pozyx.CAE.Lib.Runners
                 nextStep = new BoolArrayCellSpace();
                 nextStep.Initialize(nextStepLength, nextStepOffset);
 
+                var rule = rules[ruleIndex];
+                ruleIndex = (ruleIndex + 1) % rules.Length;
+
                 var cellActionsForStep = new List<Action>(Environment.ProcessorCount);               
 
                 var iterationsPerCore = nextStepLength / Environment.ProcessorCount;
@@ -0,0 +1,60 @@
+﻿using System;
+using System.IO;
+using System.Linq;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using Pozyx.CAE.Lib.CellSpaces;
+
+namespace Pozyx.CAE.Test
+{
+    [TestClass]
+    public class CellSpaceToolsTest
+    {
+        [TestMethod]
+        public void TestParseInitialState()
+        {
+            var bitArray = CellSpaceTools.ParseInitialState("0110");
+
+            CollectionAssert.AreEqual(new[] { false, true, true, false }, bitArray.Cast<bool>().ToArray());
+        }
+
+        [TestMethod]
+        public void TestReadInitialState()
+        {
+            using (var reader = new StringReader("1001\r\n"))
+            {
+                var bitArray = CellSpaceTools.ReadInitialState(reader);
+
+                CollectionAssert.AreEqual(new[] { true, false, false, true }, bitArray.Cast<bool>().ToArray());
+            }
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestParseInitialStateInvalidCell()
+        {
+            CellSpaceTools.ParseInitialState("01x0");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestParseInitialStateEmpty()
+        {
+            CellSpaceTools.ParseInitialState("");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestReadInitialStateOnlyWhitespace()
+        {
+            using (var reader = new StringReader("\n"))
+                CellSpaceTools.ReadInitialState(reader);
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentNullException))]
+        public void TestParseInitialStateNull()
+        {
+            CellSpaceTools.ParseInitialState(null);
+        }
+    }
+}
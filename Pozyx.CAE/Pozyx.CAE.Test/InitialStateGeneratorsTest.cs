@@ -0,0 +1,84 @@
+﻿using System;
+using System.Collections;
+using System.Linq;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using Pozyx.CAE.Lib.InitialStates;
+
+namespace Pozyx.CAE.Test
+{
+    [TestClass]
+    public class InitialStateGeneratorsTest
+    {
+        [TestMethod]
+        public void TestAlternating()
+        {
+            Assert.AreEqual("1010101", ToBitString(InitialStateGenerators.GetGenerator("alternating").Generate(7)));
+        }
+
+        [TestMethod]
+        public void TestBlock()
+        {
+            Assert.AreEqual("111000111000", ToBitString(InitialStateGenerators.GetGenerator("block(3)").Generate(12)));
+            Assert.AreEqual("11", ToBitString(InitialStateGenerators.GetGenerator("block(5)").Generate(2)));
+        }
+
+        [TestMethod]
+        public void TestPrimes()
+        {
+            Assert.AreEqual("00110101000101000101", ToBitString(InitialStateGenerators.GetGenerator("primes").Generate(20)));
+            Assert.AreEqual("", ToBitString(InitialStateGenerators.GetGenerator("primes").Generate(0)));
+            Assert.AreEqual("0", ToBitString(InitialStateGenerators.GetGenerator("primes").Generate(1)));
+        }
+
+        [TestMethod]
+        public void TestRandomWalk()
+        {
+            const int length = 101;
+
+            var bitArray = InitialStateGenerators.GetGenerator("random-walk", 42).Generate(length);
+            var bits = ToBitString(bitArray);
+
+            Assert.AreEqual(length, bitArray.Length);
+            Assert.AreEqual(bits, ToBitString(InitialStateGenerators.GetGenerator("random-walk", 42).Generate(length)), "Same seed, same walk");
+
+            // walker starts in the middle and visits a contiguous range
+            Assert.AreEqual('1', bits[length / 2]);
+            Assert.IsFalse(bits.Trim('0').Contains('0'), "Visited cells are contiguous");
+
+            Assert.AreEqual("1", ToBitString(InitialStateGenerators.GetGenerator("random-walk").Generate(1)));
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestUnknownGenerator()
+        {
+            InitialStateGenerators.GetGenerator("checkerboard");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestInvalidBlockSize()
+        {
+            InitialStateGenerators.GetGenerator("block(0)");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestNonNumericBlockSize()
+        {
+            InitialStateGenerators.GetGenerator("block(x)");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentNullException))]
+        public void TestNullGenerator()
+        {
+            InitialStateGenerators.GetGenerator(null);
+        }
+
+        private static string ToBitString(BitArray bitArray)
+        {
+            return new string(bitArray.Cast<bool>().Select(b => b ? '1' : '0').ToArray());
+        }
+    }
+}
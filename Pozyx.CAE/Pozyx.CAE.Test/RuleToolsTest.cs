@@ -0,0 +1,61 @@
+﻿using System;
+using System.Linq;
+using Microsoft.VisualStudio.TestTools.UnitTesting;
+using Pozyx.CAE.Lib;
+
+namespace Pozyx.CAE.Test
+{
+    [TestClass]
+    public class RuleToolsTest
+    {
+        [TestMethod]
+        public void TestGetRuleNumberForTable()
+        {
+            Assert.AreEqual(110, RuleTools.GetRuleNumberForTable("01101110"));
+            Assert.AreEqual(30, RuleTools.GetRuleNumberForTable("00011110"));
+        }
+
+        [TestMethod]
+        public void TestGetRuleNumberForTableAgreesWithRuleBits()
+        {
+            for (var ruleNumber = 0; ruleNumber < 256; ruleNumber++)
+            {
+                var ruleTable = Convert.ToString(ruleNumber, 2).PadLeft(8, '0');
+
+                // rule bits are indexed by neighborhood (bits[0] is 000), the table starts with 111
+                var bits = ruleTable.Reverse().Select(transition => transition == '1').ToArray();
+
+                Assert.AreEqual(ruleNumber, RuleTools.GetRuleNumberForTable(ruleTable), $"Rule table {ruleTable}");
+                Assert.AreEqual(ruleNumber, RuleTools.ConvertBitsToByte(bits), $"Rule table {ruleTable}");
+            }
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestGetRuleNumberForTableTooShort()
+        {
+            RuleTools.GetRuleNumberForTable("0110111");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestGetRuleNumberForTableTooLong()
+        {
+            RuleTools.GetRuleNumberForTable("011011100");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestGetRuleNumberForTableInvalidTransition()
+        {
+            RuleTools.GetRuleNumberForTable("0110111x");
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentNullException))]
+        public void TestGetRuleNumberForTableNull()
+        {
+            RuleTools.GetRuleNumberForTable(null);
+        }
+    }
+}
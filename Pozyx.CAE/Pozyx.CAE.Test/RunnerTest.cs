@@ -1,10 +1,12 @@
 ﻿using System;
+using System.Collections;
 using System.Collections.Generic;
 using System.Diagnostics;
 using System.Globalization;
 using System.IO;
 using System.Linq;
 using System.Reactive.Linq;
+using System.Reactive.Subjects;
 using System.Threading;
 using Microsoft.VisualStudio.TestTools.UnitTesting;
 using Pozyx.CAE.Lib;
@@ -133,6 +135,116 @@ namespace Pozyx.CAE.Test
             TestRunner(new SingleThreadCpuRunner<PackedIntArrayCellSpace>());
         }
 
+        [TestMethod]
+        public void TestBitwiseSingleThreadCpuPackedLongRunner()
+        {
+            TestRunner(new BitwiseSingleThreadCpuPackedLongRunner());
+        }
+
+        [TestMethod]
+        public void TestBitwiseSingleThreadCpuPackedLongRunnerAndCompareWithRef()
+        {
+            using (var runner = new BitwiseSingleThreadCpuPackedLongRunner())
+                TestRunnerAndCompareWithRef(runner, 110, 5);
+        }
+
+        [TestMethod]
+        public void TestBitwiseSingleThreadCpuPackedLongRunnerAllRules()
+        {
+            // rows grow by up to 2 cells per generation, so this crosses several 64-cell words;
+            // rules mapping 000 to 1 (odd rule numbers) check that bits past the last cell stay cleared
+            const int generations = 300;
+
+            for (var ruleNumber = 0; ruleNumber < 256; ruleNumber++)
+            {
+                List<PackedLongArrayCellSpace> result;
+                using (var runner = new BitwiseSingleThreadCpuPackedLongRunner())
+                    result = RunGenerations(runner, ruleNumber, generations);
+
+                List<BoolArrayCellSpace> referenceResult;
+                using (var refRunner = new SingleThreadCpuRunner<BoolArrayCellSpace>())
+                    referenceResult = RunGenerations(refRunner, ruleNumber, generations);
+
+                Assert.AreEqual(referenceResult.Count, result.Count, $"Rule {ruleNumber}: number of generations");
+
+                for (var generation = 0; generation < result.Count; generation++)
+                    AssertCellSpacesEqual(referenceResult[generation], result[generation], $"Rule {ruleNumber}, generation {generation}");
+            }
+        }
+
+        [TestMethod]
+        public void TestRunnerInitialState()
+        {
+            const int generations = 100;
+            const int offset = -137;
+
+            var random = new Random(0);
+            var initialState = new BitArray(Enumerable.Range(0, 200).Select(i => random.Next(2) == 1).ToArray());
+
+            for (var ruleNumber = 0; ruleNumber < 256; ruleNumber++)
+            {
+                // SingleThreadCpuRunner has its own loop, so it is independent of StepCpuSyncedRunner
+                List<BoolArrayCellSpace> referenceResult;
+                using (var refRunner = new SingleThreadCpuRunner<BoolArrayCellSpace>())
+                    referenceResult = RunGenerations(ct => refRunner.Create(ruleNumber, initialState, offset, ct), generations);
+
+                AssertInitialGenerations(referenceResult, ruleNumber, initialState, offset);
+
+                List<BoolArrayCellSpace> optimizedResult;
+                using (var runner = new OptimizedSingleThreadCpuRunner())
+                    optimizedResult = RunGenerations(ct => runner.Create(ruleNumber, initialState, offset, ct), generations);
+
+                List<PackedLongArrayCellSpace> bitwiseResult;
+                using (var runner = new BitwiseSingleThreadCpuPackedLongRunner())
+                    bitwiseResult = RunGenerations(ct => runner.Create(ruleNumber, initialState, offset, ct), generations);
+
+                Assert.AreEqual(referenceResult.Count, optimizedResult.Count, $"Rule {ruleNumber}: number of generations");
+                Assert.AreEqual(referenceResult.Count, bitwiseResult.Count, $"Rule {ruleNumber}: number of generations");
+
+                for (var generation = 0; generation < referenceResult.Count; generation++)
+                {
+                    AssertCellSpacesEqual(referenceResult[generation], optimizedResult[generation], $"Rule {ruleNumber}, generation {generation}");
+                    AssertCellSpacesEqual(referenceResult[generation], bitwiseResult[generation], $"Rule {ruleNumber}, generation {generation}");
+                }
+            }
+        }
+
+        [TestMethod]
+        public void TestRunnerRuleSchedule()
+        {
+            const int generations = 60;
+            const int offset = -20;
+
+            var random = new Random(0);
+            var initialState = new BitArray(Enumerable.Range(0, 40).Select(i => random.Next(2) == 1).ToArray());
+
+            // 1 and 255 map 000 to 1
+            var schedules = new[] { new[] { 30, 90 }, new[] { 110, 1, 255 }, new[] { 90 } };
+
+            foreach (var ruleNumbers in schedules)
+            {
+                using (var runner = new SingleThreadCpuRunner<BoolArrayCellSpace>())
+                    AssertRuleSchedule(RunGenerations(ct => runner.Create(ruleNumbers, initialState, offset, ct), generations), ruleNumbers, runner.GetType().Name);
+
+                using (var runner = new OptimizedSingleThreadCpuRunner())
+                    AssertRuleSchedule(RunGenerations(ct => runner.Create(ruleNumbers, initialState, offset, ct), generations), ruleNumbers, runner.GetType().Name);
+
+                using (var runner = new TaskPerCoreStepCpuRunner())
+                    AssertRuleSchedule(RunGenerations(ct => runner.Create(ruleNumbers, initialState, offset, ct), generations), ruleNumbers, runner.GetType().Name);
+
+                using (var runner = new BitwiseSingleThreadCpuPackedLongRunner())
+                    AssertRuleSchedule(RunGenerations(ct => runner.Create(ruleNumbers, initialState, offset, ct), generations), ruleNumbers, runner.GetType().Name);
+            }
+        }
+
+        [TestMethod]
+        [ExpectedException(typeof(ArgumentException))]
+        public void TestRunnerEmptyRuleSchedule()
+        {
+            using (var runner = new OptimizedSingleThreadCpuRunner())
+                runner.Create(new int[0], new BitArray(1, true), 0, CancellationToken.None);
+        }
+
         [TestMethod]
         public void TestCppSingleThreadCpuPackedIntRunner()
         {
@@ -286,6 +398,92 @@ namespace Pozyx.CAE.Test
             }                  
         }
 
+        private static List<TCellSpace> RunGenerations<TCellSpace>(IRunner<TCellSpace> runner, int ruleNumber, int generations)
+            where TCellSpace : ICellSpace, new()
+        {
+            return RunGenerations(ct => runner.Create(ruleNumber, ct), generations);
+        }
+
+        private static List<TCellSpace> RunGenerations<TCellSpace>(
+            Func<CancellationToken, IConnectableObservable<TCellSpace>> createObservable, int generations)
+        {
+            var cts = new CancellationTokenSource();
+
+            var connectableOutputObservable = createObservable(cts.Token);
+
+            var outputObservable = connectableOutputObservable.Take(generations).ToList().PublishLast();
+            outputObservable.Connect();
+
+            connectableOutputObservable.Connect();
+
+            var output = outputObservable.Wait();
+
+            cts.Cancel();
+
+            return output.ToList();
+        }
+
+        // checks generations 0 and 1 against the rule applied directly to initialState
+        private static void AssertInitialGenerations(IList<BoolArrayCellSpace> result, int ruleNumber, BitArray initialState, int offset)
+        {
+            Assert.IsTrue(result.Count >= 2, $"Rule {ruleNumber}: at least 2 generations expected");
+
+            Assert.AreEqual(offset, result[0].Offset, $"Rule {ruleNumber}: generation 0 offset");
+            Assert.AreEqual(initialState.Length, result[0].Length, $"Rule {ruleNumber}: generation 0 length");
+
+            for (var i = 0; i < initialState.Length; i++)
+                Assert.AreEqual(initialState[i], result[0].Get(offset + i), $"Rule {ruleNumber}: generation 0 cell {offset + i}");
+
+            Assert.AreEqual(offset - 1, result[1].Offset, $"Rule {ruleNumber}: generation 1 offset");
+            Assert.AreEqual(initialState.Length + 2, result[1].Length, $"Rule {ruleNumber}: generation 1 length");
+
+            Func<int, int> getInitialCell = index =>
+                index - offset >= 0 && index - offset < initialState.Length && initialState[index - offset] ? 1 : 0;
+
+            for (var index = offset - 1; index < offset + initialState.Length + 1; index++)
+            {
+                var neighborhood = getInitialCell(index - 1) << 2 | getInitialCell(index) << 1 | getInitialCell(index + 1);
+
+                Assert.AreEqual(((ruleNumber >> neighborhood) & 1) == 1, result[1].Get(index), $"Rule {ruleNumber}: generation 1 cell {index}");
+            }
+        }
+
+        // every generation must be the scheduled rule applied to the previous one
+        private static void AssertRuleSchedule<TCellSpace>(IList<TCellSpace> result, int[] ruleNumbers, string runnerName)
+            where TCellSpace : ICellSpace
+        {
+            var schedule = string.Join(",", ruleNumbers);
+
+            Assert.IsTrue(result.Count > 1, $"{runnerName}, rules {schedule}: at least 2 generations expected");
+
+            for (var generation = 1; generation < result.Count; generation++)
+            {
+                var ruleNumber = ruleNumbers[(generation - 1) % ruleNumbers.Length];
+                var prevStep = result[generation - 1];
+                var nextStep = result[generation];
+
+                for (var index = nextStep.Offset; index < nextStep.Offset + nextStep.Length; index++)
+                {
+                    var neighborhood =
+                        (prevStep.Get(index - 1) ? 4 : 0) |
+                        (prevStep.Get(index) ? 2 : 0) |
+                        (prevStep.Get(index + 1) ? 1 : 0);
+
+                    Assert.AreEqual(((ruleNumber >> neighborhood) & 1) == 1, nextStep.Get(index),
+                        $"{runnerName}, rules {schedule}: generation {generation} cell {index}");
+                }
+            }
+        }
+
+        private static void AssertCellSpacesEqual(ICellSpace expected, ICellSpace actual, string message)
+        {
+            Assert.AreEqual(expected.Offset, actual.Offset, $"{message}: offset");
+            Assert.AreEqual(expected.Length, actual.Length, $"{message}: length");
+
+            for (var i = expected.Offset; i < expected.Offset + expected.Length; i++)
+                Assert.AreEqual(expected.Get(i), actual.Get(i), $"{message}: cell {i}");
+        }
+
         private static string GetTestOutputFileName<TCellSpace>
             (IRunner<TCellSpace> runner, int ruleNumber) where TCellSpace : ICellSpace, new()
         {